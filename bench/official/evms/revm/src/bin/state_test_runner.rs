@@ -0,0 +1,466 @@
+// Conformance runner for standard Ethereum `GeneralStateTests` JSON fixtures.
+// Builds the pre-state into a `CacheDB`, runs each (fork, data-index,
+// gas-index, value-index) case through revm, and checks both the resulting
+// post-state root and the expected_exception string from the test vector -
+// so a test that is supposed to fail (e.g. `TR_EMPTYBLOB`) is only a pass if
+// revm actually rejects it for that reason.
+
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use alloy_trie::{
+    root::{state_root_unsorted, storage_root_unsorted},
+    TrieAccount,
+};
+use revm::{
+    db::{AccountState, CacheDB, DbAccount, EmptyDB},
+    primitives::{
+        keccak256, AccountInfo, Address, Bytecode, Bytes, EVMError, Env, SpecId, TransactTo, B256,
+        KECCAK_EMPTY, U256,
+    },
+    DatabaseCommit, Evm,
+};
+use secp256k1::{Secp256k1, SecretKey};
+use serde::Deserialize;
+
+/// Error taxonomy for a single (fork, data, gas, value) sub-test.
+#[derive(Debug)]
+enum TestErrorKind {
+    /// Execution completed but the resulting state root didn't match the vector.
+    StateRootMismatch { got: B256, expected: B256 },
+    /// The vector expected a specific exception string but execution either
+    /// succeeded or failed for a different reason, or the vector expected no
+    /// exception but the transaction was rejected (`expected` is `None`).
+    UnexpectedException {
+        expected: Option<String>,
+        got: Option<String>,
+    },
+    /// The transaction's `secretKey` didn't derive to a valid secp256k1 key.
+    UnknownPrivateKey,
+    /// Any other revm-level execution error (out of gas on setup, bad RLP, etc).
+    Execution(String),
+}
+
+impl std::fmt::Display for TestErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestErrorKind::StateRootMismatch { got, expected } => {
+                write!(f, "state root mismatch: got {got}, expected {expected}")
+            }
+            TestErrorKind::UnexpectedException { expected, got } => write!(
+                f,
+                "exception mismatch: expected {:?}, got {:?}",
+                expected, got
+            ),
+            TestErrorKind::UnknownPrivateKey => write!(f, "unknown private key"),
+            TestErrorKind::Execution(msg) => write!(f, "execution error: {msg}"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TestEnv {
+    #[serde(rename = "currentCoinbase")]
+    current_coinbase: Address,
+    #[serde(rename = "currentGasLimit")]
+    current_gas_limit: U256,
+    #[serde(rename = "currentNumber")]
+    current_number: U256,
+    #[serde(rename = "currentTimestamp")]
+    current_timestamp: U256,
+    #[serde(rename = "currentBaseFee", default)]
+    current_base_fee: Option<U256>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PreAccount {
+    balance: U256,
+    code: Bytes,
+    nonce: U256,
+    storage: HashMap<U256, U256>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Indexes {
+    data: usize,
+    gas: usize,
+    value: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct PostState {
+    hash: B256,
+    indexes: Indexes,
+    #[serde(default, rename = "expectException")]
+    expect_exception: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestTransaction {
+    data: Vec<Bytes>,
+    #[serde(rename = "gasLimit")]
+    gas_limit: Vec<U256>,
+    #[serde(rename = "gasPrice", default)]
+    gas_price: Option<U256>,
+    nonce: U256,
+    #[serde(default)]
+    to: Option<Address>,
+    value: Vec<U256>,
+    #[serde(rename = "secretKey")]
+    secret_key: B256,
+}
+
+#[derive(Debug, Deserialize)]
+struct StateTestCase {
+    env: TestEnv,
+    pre: HashMap<Address, PreAccount>,
+    post: HashMap<String, Vec<PostState>>,
+    transaction: TestTransaction,
+}
+
+/// A single fixture file is a map of test-name to test case.
+type StateTestFile = HashMap<String, StateTestCase>;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let mut tests_path = String::new();
+    let mut skip_list_path = String::new();
+
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--tests-path" => {
+                tests_path = args[i + 1].clone();
+                i += 2;
+            }
+            "--skip-list" => {
+                skip_list_path = args[i + 1].clone();
+                i += 2;
+            }
+            _ => i += 1,
+        }
+    }
+
+    let skip_list: Vec<String> = if skip_list_path.is_empty() {
+        Vec::new()
+    } else {
+        fs::read_to_string(&skip_list_path)
+            .unwrap()
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .collect()
+    };
+
+    let mut files = Vec::new();
+    collect_json_files(Path::new(&tests_path), &mut files);
+    files.sort();
+
+    let mut total_pass = 0usize;
+    let mut total_fail = 0usize;
+
+    for file in &files {
+        let file_name = file.file_name().unwrap().to_string_lossy().to_string();
+        if skip_list.iter().any(|skip| file_name.contains(skip.as_str())) {
+            println!("SKIP {file_name}");
+            continue;
+        }
+
+        let (pass, fail) = run_file(file);
+        total_pass += pass;
+        total_fail += fail;
+        println!("{file_name}: {pass} passed, {fail} failed");
+    }
+
+    println!("TOTAL: {total_pass} passed, {total_fail} failed");
+    if total_fail > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn collect_json_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_json_files(&path, out);
+        } else if path.extension().map(|ext| ext == "json").unwrap_or(false) {
+            out.push(path);
+        }
+    }
+}
+
+fn run_file(path: &Path) -> (usize, usize) {
+    let raw = fs::read_to_string(path).unwrap();
+    let file: StateTestFile = match serde_json::from_str(&raw) {
+        Ok(file) => file,
+        Err(err) => {
+            println!("  parse error: {err}");
+            return (0, 1);
+        }
+    };
+
+    let mut pass = 0;
+    let mut fail = 0;
+
+    for (name, case) in &file {
+        for (fork, post_states) in &case.post {
+            let Some(spec_id) = fork_to_spec_id(fork) else {
+                continue; // unknown/unsupported fork name - not scored either way
+            };
+
+            for post in post_states {
+                match run_case(case, spec_id, post) {
+                    Ok(()) => pass += 1,
+                    Err(err) => {
+                        fail += 1;
+                        println!("  FAIL {name}[{fork}]({},{},{}): {err}", post.indexes.data, post.indexes.gas, post.indexes.value);
+                    }
+                }
+            }
+        }
+    }
+
+    (pass, fail)
+}
+
+fn fork_to_spec_id(fork: &str) -> Option<SpecId> {
+    match fork {
+        "Frontier" => Some(SpecId::FRONTIER),
+        "Homestead" => Some(SpecId::HOMESTEAD),
+        "Byzantium" => Some(SpecId::BYZANTIUM),
+        "Constantinople" => Some(SpecId::CONSTANTINOPLE),
+        "Istanbul" => Some(SpecId::ISTANBUL),
+        "Berlin" => Some(SpecId::BERLIN),
+        "London" => Some(SpecId::LONDON),
+        "Merge" => Some(SpecId::MERGE),
+        "Shanghai" => Some(SpecId::SHANGHAI),
+        "Cancun" => Some(SpecId::CANCUN),
+        _ => None,
+    }
+}
+
+fn run_case(case: &StateTestCase, spec_id: SpecId, post: &PostState) -> Result<(), TestErrorKind> {
+    let secret_key = SecretKey::from_slice(case.transaction.secret_key.as_slice())
+        .map_err(|_| TestErrorKind::UnknownPrivateKey)?;
+    let caller = secret_key_to_address(&secret_key);
+
+    let mut db = CacheDB::new(EmptyDB::default());
+    for (address, account) in &case.pre {
+        let info = AccountInfo {
+            balance: account.balance,
+            nonce: account.nonce.try_into().unwrap_or(u64::MAX),
+            code_hash: revm::primitives::keccak256(&account.code),
+            code: Some(Bytecode::new_raw(account.code.clone())),
+        };
+        db.insert_account_info(*address, info);
+        for (slot, value) in &account.storage {
+            db.insert_account_storage(*address, *slot, *value).ok();
+        }
+    }
+
+    let data = case
+        .transaction
+        .data
+        .get(post.indexes.data)
+        .cloned()
+        .unwrap_or_default();
+    let gas_limit = case
+        .transaction
+        .gas_limit
+        .get(post.indexes.gas)
+        .copied()
+        .unwrap_or(U256::ZERO);
+    let value = case
+        .transaction
+        .value
+        .get(post.indexes.value)
+        .copied()
+        .unwrap_or(U256::ZERO);
+
+    let env = build_env(
+        &case.env,
+        caller,
+        data,
+        gas_limit,
+        value,
+        case.transaction.to,
+        case.transaction.gas_price,
+    );
+
+    let exec_result = {
+        let mut evm = Evm::builder()
+            .with_db(&mut db)
+            .with_env(Box::new(env))
+            .with_spec_id(spec_id)
+            .build();
+        evm.transact()
+    };
+
+    // `expectException` in GeneralStateTests signals that the *transaction*
+    // itself is invalid (bad nonce, insufficient balance, malformed blob
+    // fields, ...) and is rejected by `evm.transact()` before any EVM code
+    // runs. A `Revert`/`Halt` is a legitimate, valid-transaction outcome
+    // (the post-state still needs checking), not a vector-level exception.
+    let result = match (&post.expect_exception, exec_result) {
+        (Some(expected), Err(err)) => {
+            let actual = classify_tx_error(&err);
+            return if exception_matches(expected, &actual) {
+                Ok(())
+            } else {
+                Err(TestErrorKind::UnexpectedException {
+                    expected: Some(expected.clone()),
+                    got: Some(actual),
+                })
+            };
+        }
+        (Some(expected), Ok(_)) => {
+            return Err(TestErrorKind::UnexpectedException {
+                expected: Some(expected.clone()),
+                got: None,
+            });
+        }
+        (None, Err(err)) => {
+            return Err(TestErrorKind::UnexpectedException {
+                expected: None,
+                got: Some(classify_tx_error(&err)),
+            });
+        }
+        (None, Ok(result)) => result,
+    };
+
+    db.commit(result.state);
+
+    let got_root = state_root(&db);
+    if got_root != post.hash {
+        return Err(TestErrorKind::StateRootMismatch {
+            got: got_root,
+            expected: post.hash,
+        });
+    }
+
+    Ok(())
+}
+
+fn build_env(
+    test_env: &TestEnv,
+    caller: Address,
+    data: Bytes,
+    gas_limit: U256,
+    value: U256,
+    to: Option<Address>,
+    gas_price: Option<U256>,
+) -> Env {
+    let mut env = Env::default();
+    env.block.coinbase = test_env.current_coinbase;
+    env.block.gas_limit = test_env.current_gas_limit;
+    env.block.number = test_env.current_number;
+    env.block.timestamp = test_env.current_timestamp;
+    if let Some(base_fee) = test_env.current_base_fee {
+        env.block.basefee = base_fee;
+    }
+
+    env.tx.caller = caller;
+    env.tx.transact_to = match to {
+        Some(address) => TransactTo::Call(address),
+        None => TransactTo::Create,
+    };
+    env.tx.value = value;
+    env.tx.data = data;
+    env.tx.gas_limit = gas_limit.try_into().unwrap_or(u64::MAX);
+    // The vector's own `gasPrice` is what's actually validated against the
+    // block's base fee - falling back to the base fee itself only when the
+    // vector doesn't specify one (e.g. a type-2 vector carries maxFee/tip
+    // instead, which isn't modeled here).
+    env.tx.gas_price = gas_price.unwrap_or_else(|| test_env.current_base_fee.unwrap_or(U256::ZERO));
+
+    env
+}
+
+/// Maps a failed `evm.transact()` call to a short name comparable against a
+/// vector's `expectException` string. revm's `InvalidTransaction` variants
+/// don't share a naming scheme with the `TR_*` strings used by the official
+/// fixtures, so this is intentionally coarse - see `exception_matches`.
+fn classify_tx_error<DbErr: std::fmt::Debug>(err: &EVMError<DbErr>) -> String {
+    match err {
+        EVMError::Transaction(invalid) => format!("{invalid:?}"),
+        other => format!("{other:?}"),
+    }
+}
+
+/// Cross-client exception taxonomies don't line up 1:1 (revm's
+/// `InvalidTransaction::NonceTooLow { .. }` vs. the vector's
+/// `TR_NonceHasMaxValue`, blob-specific `TR_EMPTYBLOB`/`TR_BLOBVERSION_INVALID`,
+/// etc.), so this matches loosely by normalizing both sides (case, `TR_`
+/// prefix, separators) and checking containment either way rather than
+/// requiring byte-identical names.
+fn exception_matches(expected: &str, actual: &str) -> bool {
+    fn normalize(s: &str) -> String {
+        s.to_ascii_uppercase()
+            .trim_start_matches("TR_")
+            .replace(['_', '-', ' '], "")
+    }
+
+    let (expected, actual) = (normalize(expected), normalize(actual));
+    !expected.is_empty() && !actual.is_empty() && (expected.contains(&actual) || actual.contains(&expected))
+}
+
+fn secret_key_to_address(secret_key: &SecretKey) -> Address {
+    let secp = Secp256k1::new();
+    let public_key = secret_key.public_key(&secp);
+    let uncompressed = public_key.serialize_uncompressed();
+    let hash = revm::primitives::keccak256(&uncompressed[1..]);
+    Address::from_slice(&hash[12..])
+}
+
+/// Computes the real Keccak Merkle-Patricia-Trie state root over the
+/// post-execution accounts, the same way the reference clients that produced
+/// `post.hash` do: each account's storage is its own MPT keyed by
+/// `keccak256(slot)`, and the state trie is keyed by `keccak256(address)`
+/// with an RLP-encoded `(nonce, balance, storage_root, code_hash)` leaf.
+///
+/// `CacheDB::commit` never deletes map entries - a selfdestructed account
+/// stays as `AccountState::NotExisting`, and an EIP-161 touched-but-empty
+/// account stays with a zeroed `AccountInfo`. Neither is part of the real
+/// state trie, so both are filtered out here before hashing.
+fn state_root(db: &CacheDB<EmptyDB>) -> B256 {
+    state_root_unsorted(
+        db.accounts
+            .iter()
+            .filter(|(_, account)| !is_removed_or_empty(account))
+            .map(|(address, account)| {
+                let storage_root =
+                    account_storage_root(account.storage.iter().map(|(k, v)| (*k, *v)));
+                (
+                    keccak256(address),
+                    TrieAccount {
+                        nonce: account.info.nonce,
+                        balance: account.info.balance,
+                        storage_root,
+                        code_hash: account.info.code_hash,
+                    },
+                )
+            }),
+    )
+}
+
+fn is_removed_or_empty(account: &DbAccount) -> bool {
+    if matches!(account.account_state, AccountState::NotExisting) {
+        return true;
+    }
+    account.info.nonce == 0 && account.info.balance.is_zero() && account.info.code_hash == KECCAK_EMPTY
+}
+
+fn account_storage_root(storage: impl Iterator<Item = (U256, U256)>) -> B256 {
+    storage_root_unsorted(
+        storage
+            .filter(|(_, value)| !value.is_zero())
+            .map(|(slot, value)| (keccak256(slot.to_be_bytes::<32>()), value)),
+    )
+}
@@ -1,21 +1,38 @@
-use std::{env, fs, str::FromStr, time::Instant};
+use std::{collections::BTreeMap, env, fs, str::FromStr, time::Instant};
 
 use revm::{
-    primitives::{Address, Bytes, ExecutionResult, TxKind, U256},
+    inspector_handle_register,
+    interpreter::{opcode::OPCODE_JUMPMAP, Interpreter},
+    primitives::{keccak256, Address, Bytes, ExecutionResult, Output, TxKind, U256},
     db::{CacheDB, EmptyDB},
-    Evm, DatabaseCommit,
+    Database, Evm, DatabaseCommit, EvmContext, Inspector,
 };
+use secp256k1::{ecdsa::RecoverableSignature, Message, Secp256k1, SecretKey};
+use serde_json::json;
 
 const CALLER_ADDRESS: &str = "0x1000000000000000000000000000000000000001";
 
+// Fixed secp256k1 private key used to derive the ecrecover benchmark's
+// signature/digest/recovery-id triple. Not a real key - just needs to be valid.
+const ECRECOVER_PRIVATE_KEY: &str = "1000000000000000000000000000000000000000000000000000000000000001";
+
+const ECRECOVER_ADDRESS: u8 = 0x01;
+const ECADD_ADDRESS: u8 = 0x06;
+const ECMUL_ADDRESS: u8 = 0x07;
+const ECPAIRING_ADDRESS: u8 = 0x08;
+
 fn main() {
     let args: Vec<String> = env::args().collect();
-    
+
     // Fast argument parsing without validation
     let mut contract_code_path = String::new();
     let mut calldata_hex = String::new();
     let mut num_runs: u8 = 1;
-    
+    let mut precompile = String::new();
+    let mut pairing_k: usize = 2;
+    let mut profile = false;
+    let mut emit_result = false;
+
     let mut i = 1;
     while i < args.len() {
         match args[i].as_str() {
@@ -31,19 +48,30 @@ fn main() {
                 num_runs = args[i + 1].parse().unwrap();
                 i += 2;
             }
+            "--precompile" => {
+                precompile = args[i + 1].clone();
+                i += 2;
+            }
+            "--pairing-k" => {
+                pairing_k = args[i + 1].parse().unwrap();
+                i += 2;
+            }
+            "--profile" => {
+                profile = true;
+                i += 1;
+            }
+            "--emit-result" => {
+                emit_result = true;
+                i += 1;
+            }
             _ => i += 1,
         }
     }
-    
+
     let caller_address = Address::from_str(CALLER_ADDRESS).unwrap();
-    
-    // Read and decode without error handling for performance
-    let contract_code_hex = fs::read_to_string(&contract_code_path).unwrap().trim().to_string();
-    let contract_code: Bytes = hex::decode(contract_code_hex.trim_start_matches("0x")).unwrap().into();
-    let calldata: Bytes = hex::decode(calldata_hex.trim_start_matches("0x")).unwrap().into();
-    
+
     let mut db = CacheDB::new(EmptyDB::default());
-    
+
     // Set up caller with large balance
     let caller_info = revm::primitives::AccountInfo {
         balance: U256::MAX,
@@ -52,10 +80,30 @@ fn main() {
         code: None,
     };
     db.insert_account_info(caller_address, caller_info);
-    
+
+    if !precompile.is_empty() {
+        run_precompile_benchmark(&mut db, caller_address, &precompile, pairing_k, num_runs);
+        return;
+    }
+
+    // Read and decode without error handling for performance
+    let contract_code_hex = fs::read_to_string(&contract_code_path).unwrap().trim().to_string();
+    let contract_code: Bytes = hex::decode(contract_code_hex.trim_start_matches("0x")).unwrap().into();
+    let calldata: Bytes = hex::decode(calldata_hex.trim_start_matches("0x")).unwrap().into();
+
     // Deploy the contract first
     let contract_address = deploy_contract(&mut db, caller_address, &contract_code).unwrap();
-    
+
+    if profile {
+        run_profiled_benchmark(&mut db, caller_address, contract_address, &calldata, num_runs);
+        return;
+    }
+
+    if emit_result {
+        run_emit_result(&mut db, caller_address, contract_address, &calldata);
+        return;
+    }
+
     // Create EVM instance once - outside the loop (like Zig does)
     let mut evm = Evm::builder()
         .with_db(&mut db)
@@ -68,20 +116,420 @@ fn main() {
             tx.gas_price = U256::from(0u64);
         })
         .build();
-    
+
     // Run the benchmark num_runs times
     for _ in 0..num_runs {
         let timer = Instant::now();
-        
+
         // Execute without error handling for performance
         let result = evm.transact().unwrap();
         let dur = timer.elapsed();
-        
+
         // Commit the state changes (similar to how Zig's vm.call_contract works)
         evm.context.evm.db.commit(result.state);
-        
+
+        println!("{}", dur.as_micros() as f64 / 1e3);
+    }
+}
+
+/// Per-opcode timing/gas accumulated by `OpcodeProfiler` across a run.
+#[derive(Default, Clone, Copy)]
+struct OpcodeStats {
+    count: u64,
+    gas: u64,
+    nanos: u64,
+}
+
+/// Inspector that samples wall-clock time and gas charged around each
+/// `step`, so `--profile` can report a per-opcode breakdown instead of a
+/// single aggregate wall-clock number.
+struct OpcodeProfiler {
+    stats: [OpcodeStats; 256],
+    step_started_at: Instant,
+    step_started_gas: u64,
+    step_opcode: u8,
+}
+
+impl Default for OpcodeProfiler {
+    fn default() -> Self {
+        Self {
+            stats: [OpcodeStats::default(); 256],
+            step_started_at: Instant::now(),
+            step_started_gas: 0,
+            step_opcode: 0,
+        }
+    }
+}
+
+impl<DB: Database> Inspector<DB> for OpcodeProfiler {
+    fn step(&mut self, interp: &mut Interpreter, _context: &mut EvmContext<DB>) {
+        // Recorded here: by `step_end` the instruction pointer has moved past
+        // this opcode.
+        self.step_opcode = interp.current_opcode();
+        self.step_started_at = Instant::now();
+        self.step_started_gas = interp.gas.remaining();
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, _context: &mut EvmContext<DB>) {
+        let opcode = self.step_opcode as usize;
+        let elapsed_nanos = self.step_started_at.elapsed().as_nanos() as u64;
+        let gas_charged = self.step_started_gas.saturating_sub(interp.gas.remaining());
+
+        let entry = &mut self.stats[opcode];
+        entry.count += 1;
+        entry.gas += gas_charged;
+        entry.nanos += elapsed_nanos;
+    }
+}
+
+// Runs the benchmark with an `OpcodeProfiler` attached and prints a
+// gas-sorted per-opcode histogram after the timed runs, so the expensive
+// categories (SSTORE/SLOAD, CALL-family, SHA3 per-word, EXP per-exponent-byte,
+// memory-expansion) are easy to pick out.
+fn run_profiled_benchmark(
+    db: &mut CacheDB<EmptyDB>,
+    caller_address: Address,
+    contract_address: Address,
+    calldata: &Bytes,
+    num_runs: u8,
+) {
+    let mut evm = Evm::builder()
+        .with_db(db)
+        .with_external_context(OpcodeProfiler::default())
+        .append_handler_register(inspector_handle_register)
+        .modify_tx_env(|tx| {
+            tx.caller = caller_address;
+            tx.transact_to = TxKind::Call(contract_address);
+            tx.value = U256::ZERO;
+            tx.data = calldata.clone();
+            tx.gas_limit = 1_000_000_000; // 1B gas
+            tx.gas_price = U256::from(0u64);
+        })
+        .build();
+
+    for _ in 0..num_runs {
+        let timer = Instant::now();
+        let result = evm.transact().unwrap();
+        let dur = timer.elapsed();
+
+        evm.context.evm.db.commit(result.state);
+
         println!("{}", dur.as_micros() as f64 / 1e3);
     }
+
+    print_opcode_histogram(&evm.context.external);
+}
+
+// Runs the transaction once and prints a deterministic JSON rendering of the
+// full `ExecutionResult` (status, gas, return data, logs, storage diffs) so
+// an external harness can diff this against guillotine's Zig EVM emitting
+// the same schema for identical (bytecode, calldata, caller, gas_limit).
+fn run_emit_result(
+    db: &mut CacheDB<EmptyDB>,
+    caller_address: Address,
+    contract_address: Address,
+    calldata: &Bytes,
+) {
+    let mut evm = Evm::builder()
+        .with_db(db)
+        .modify_tx_env(|tx| {
+            tx.caller = caller_address;
+            tx.transact_to = TxKind::Call(contract_address);
+            tx.value = U256::ZERO;
+            tx.data = calldata.clone();
+            tx.gas_limit = 1_000_000_000; // 1B gas
+            tx.gas_price = U256::from(0u64);
+        })
+        .build();
+
+    let result = evm.transact().unwrap();
+    let json = execution_result_to_json(&result.result, &result.state);
+    println!("{}", serde_json::to_string(&json).unwrap());
+}
+
+fn execution_result_to_json(
+    result: &ExecutionResult,
+    state: &revm::primitives::HashMap<Address, revm::primitives::Account>,
+) -> serde_json::Value {
+    match result {
+        ExecutionResult::Success {
+            gas_used,
+            output,
+            logs,
+            ..
+        } => {
+            let return_data = match output {
+                Output::Call(data) => data,
+                Output::Create(data, _) => data,
+            };
+            json!({
+                "status": "success",
+                "gasUsed": gas_used,
+                "returnData": format!("0x{}", hex::encode(return_data)),
+                "logs": logs.iter().map(log_to_json).collect::<Vec<_>>(),
+                "storageDiffs": storage_diffs_to_json(state),
+            })
+        }
+        ExecutionResult::Revert { gas_used, output } => json!({
+            "status": "revert",
+            "gasUsed": gas_used,
+            "returnData": format!("0x{}", hex::encode(output)),
+            "logs": [],
+            "storageDiffs": storage_diffs_to_json(state),
+        }),
+        ExecutionResult::Halt { reason, gas_used } => json!({
+            "status": "halt",
+            "haltReason": format!("{reason:?}"),
+            "gasUsed": gas_used,
+            "returnData": "0x",
+            "logs": [],
+            "storageDiffs": storage_diffs_to_json(state),
+        }),
+    }
+}
+
+fn log_to_json(log: &revm::primitives::Log) -> serde_json::Value {
+    json!({
+        "address": format!("0x{}", hex::encode(log.address)),
+        "topics": log.data.topics().iter().map(|t| format!("0x{}", hex::encode(t))).collect::<Vec<_>>(),
+        "data": format!("0x{}", hex::encode(&log.data.data)),
+    })
+}
+
+// Renders the account/storage diffs from `result.state` as a map of
+// address -> sorted {slot: value}, sorted itself by address, so the output
+// is byte-identical across runs for the same execution.
+fn storage_diffs_to_json(
+    state: &revm::primitives::HashMap<Address, revm::primitives::Account>,
+) -> serde_json::Value {
+    let mut accounts: BTreeMap<String, serde_json::Value> = BTreeMap::new();
+    for (address, account) in state.iter() {
+        let mut storage: BTreeMap<String, String> = BTreeMap::new();
+        for (slot, value) in account.storage.iter() {
+            storage.insert(
+                format!("0x{}", hex::encode(slot.to_be_bytes::<32>())),
+                format!("0x{}", hex::encode(value.present_value.to_be_bytes::<32>())),
+            );
+        }
+        accounts.insert(format!("0x{}", hex::encode(address)), json!(storage));
+    }
+    json!(accounts)
+}
+
+// Buckets an opcode into the gas-cost category that makes it expensive, so
+// the histogram groups hot opcodes meaningfully instead of just listing 256
+// rows. Opcodes that don't fall into one of these categories are "Other".
+fn opcode_category(opcode: u8) -> &'static str {
+    match opcode {
+        0x54 | 0x55 => "Storage (SLOAD/SSTORE)",
+        0xf0 | 0xf1 | 0xf2 | 0xf4 | 0xf5 | 0xfa => "Call-family (CALL/CREATE)",
+        0x20 => "SHA3 (per-word)",
+        0x0a => "EXP (per-exponent-byte)",
+        0x51 | 0x52 | 0x53 => "Memory expansion (MLOAD/MSTORE)",
+        _ => "Other",
+    }
+}
+
+fn print_opcode_histogram(profiler: &OpcodeProfiler) {
+    let mut rows: Vec<(usize, &OpcodeStats)> = profiler
+        .stats
+        .iter()
+        .enumerate()
+        .filter(|(_, stats)| stats.count > 0)
+        .collect();
+    rows.sort_by(|a, b| b.1.gas.cmp(&a.1.gas));
+
+    let mut categories: BTreeMap<&'static str, OpcodeStats> = BTreeMap::new();
+    for (opcode, stats) in &rows {
+        let entry = categories.entry(opcode_category(*opcode as u8)).or_default();
+        entry.count += stats.count;
+        entry.gas += stats.gas;
+        entry.nanos += stats.nanos;
+    }
+    let mut category_rows: Vec<(&str, OpcodeStats)> = categories.into_iter().collect();
+    category_rows.sort_by(|a, b| b.1.gas.cmp(&a.1.gas));
+
+    println!("-- by category --");
+    println!("{:<28}{:>12}{:>16}{:>16}", "category", "count", "gas", "ns");
+    for (category, stats) in category_rows {
+        println!(
+            "{:<28}{:>12}{:>16}{:>16}",
+            category, stats.count, stats.gas, stats.nanos
+        );
+    }
+
+    println!("-- by opcode --");
+    println!("{:<16}{:>12}{:>16}{:>16}", "opcode", "count", "gas", "ns");
+    for (opcode, stats) in rows {
+        let name = OPCODE_JUMPMAP[opcode].unwrap_or("UNKNOWN");
+        println!(
+            "{:<16}{:>12}{:>16}{:>16}",
+            name, stats.count, stats.gas, stats.nanos
+        );
+    }
+}
+
+// Runs the EVM-boundary precompile benchmarks: deploys a tiny contract that
+// STATICCALLs the requested precompile with a fixed valid input baked into
+// its own code, then times `evm.transact()` the same way the contract-code
+// path does. This measures the full dispatch path (gas accounting, input
+// padding, return-data copy) rather than the bare arkworks operation.
+fn run_precompile_benchmark(
+    db: &mut CacheDB<EmptyDB>,
+    caller_address: Address,
+    precompile: &str,
+    pairing_k: usize,
+    num_runs: u8,
+) {
+    let (address, input, out_len) = match precompile {
+        "ecrecover" => (ECRECOVER_ADDRESS, build_ecrecover_input(), 32usize),
+        "ecadd" => (ECADD_ADDRESS, build_ecadd_input(), 64usize),
+        "ecmul" => (ECMUL_ADDRESS, build_ecmul_input(), 64usize),
+        "ecpairing" => (ECPAIRING_ADDRESS, build_ecpairing_input(pairing_k), 32usize),
+        _ => {
+            eprintln!("Error: Unknown precompile '{}'", precompile);
+            std::process::exit(1);
+        }
+    };
+
+    let caller_bytecode = build_precompile_caller_bytecode(address, &input, out_len);
+    let contract_address = deploy_contract(db, caller_address, &caller_bytecode).unwrap();
+
+    let mut evm = Evm::builder()
+        .with_db(db)
+        .modify_tx_env(|tx| {
+            tx.caller = caller_address;
+            tx.transact_to = TxKind::Call(contract_address);
+            tx.value = U256::ZERO;
+            tx.data = Bytes::new();
+            tx.gas_limit = 1_000_000_000; // 1B gas
+            tx.gas_price = U256::from(0u64);
+        })
+        .build();
+
+    for _ in 0..num_runs {
+        let timer = Instant::now();
+        let result = evm.transact().unwrap();
+        let dur = timer.elapsed();
+
+        evm.context.evm.db.commit(result.state);
+
+        println!("{}", dur.as_micros() as f64 / 1e3);
+    }
+}
+
+// Builds the 128-byte ecrecover input (hash || v || r || s) from a fixed
+// secp256k1 signature over a fixed digest.
+fn build_ecrecover_input() -> Bytes {
+    let secp = Secp256k1::new();
+    let secret_key = SecretKey::from_str(ECRECOVER_PRIVATE_KEY).unwrap();
+    let digest = keccak256(b"guillotine ecrecover precompile benchmark");
+    let message = Message::from_digest_slice(digest.as_slice()).unwrap();
+    let signature: RecoverableSignature = secp.sign_ecdsa_recoverable(&message, &secret_key);
+    let (recovery_id, compact) = signature.serialize_compact();
+
+    let mut input = Vec::with_capacity(128);
+    input.extend_from_slice(digest.as_slice()); // hash
+    input.extend_from_slice(&[0u8; 31]); // v, right-aligned
+    input.push(27 + recovery_id.to_i32() as u8);
+    input.extend_from_slice(&compact[0..32]); // r
+    input.extend_from_slice(&compact[32..64]); // s
+    input.into()
+}
+
+// BN254 generator G1 = (1, 2), used as the fixed valid point for ecadd/ecmul.
+fn g1_generator_bytes() -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1; // x = 1
+    bytes[63] = 2; // y = 2
+    bytes
+}
+
+// BN254 generator G2, encoded as the standard (x.c1, x.c0, y.c1, y.c0) words
+// used by the EVM ecpairing precompile.
+fn g2_generator_bytes() -> [u8; 128] {
+    let x_c0 = hex::decode("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed").unwrap();
+    let x_c1 = hex::decode("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2").unwrap();
+    let y_c0 = hex::decode("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa").unwrap();
+    let y_c1 = hex::decode("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b").unwrap();
+
+    let mut bytes = [0u8; 128];
+    bytes[0..32].copy_from_slice(&x_c1);
+    bytes[32..64].copy_from_slice(&x_c0);
+    bytes[64..96].copy_from_slice(&y_c1);
+    bytes[96..128].copy_from_slice(&y_c0);
+    bytes
+}
+
+// 128-byte ecadd input: two copies of the G1 generator.
+fn build_ecadd_input() -> Bytes {
+    let g1 = g1_generator_bytes();
+    let mut input = Vec::with_capacity(128);
+    input.extend_from_slice(&g1);
+    input.extend_from_slice(&g1);
+    input.into()
+}
+
+// 96-byte ecmul input: the G1 generator times a fixed scalar.
+fn build_ecmul_input() -> Bytes {
+    let g1 = g1_generator_bytes();
+    let mut input = Vec::with_capacity(96);
+    input.extend_from_slice(&g1);
+    input.extend_from_slice(&[0u8; 31]);
+    input.push(42); // scalar
+    input.into()
+}
+
+// 192*k-byte ecpairing input: k copies of the (G1, G2) generator pair.
+fn build_ecpairing_input(k: usize) -> Bytes {
+    let g1 = g1_generator_bytes();
+    let g2 = g2_generator_bytes();
+    let mut input = Vec::with_capacity(192 * k);
+    for _ in 0..k {
+        input.extend_from_slice(&g1);
+        input.extend_from_slice(&g2);
+    }
+    input.into()
+}
+
+// Builds a tiny contract that STATICCALLs `address` with `input` baked into
+// its own runtime code (copied into memory via CODECOPY), then returns the
+// first `out_len` bytes of the precompile's output.
+fn build_precompile_caller_bytecode(address: u8, input: &[u8], out_len: usize) -> Vec<u8> {
+    let input_len = input.len();
+
+    let mut code = Vec::new();
+    code.push(0x61); // PUSH2
+    code.extend_from_slice(&(input_len as u16).to_be_bytes()); // size
+    code.push(0x61); // PUSH2
+    code.extend_from_slice(&[0u8, 0u8]); // offset placeholder, patched below
+    code.push(0x60); // PUSH1
+    code.push(0x00); // destOffset
+    code.push(0x39); // CODECOPY
+    code.push(0x60); // PUSH1
+    code.push(out_len as u8); // retSize
+    code.push(0x60); // PUSH1
+    code.push(0x00); // retOffset
+    code.push(0x61); // PUSH2
+    code.extend_from_slice(&(input_len as u16).to_be_bytes()); // argsSize
+    code.push(0x60); // PUSH1
+    code.push(0x00); // argsOffset
+    code.push(0x60); // PUSH1
+    code.push(address); // precompile address
+    code.push(0x5a); // GAS
+    code.push(0xfa); // STATICCALL
+    code.push(0x50); // POP
+    code.push(0x60); // PUSH1
+    code.push(out_len as u8);
+    code.push(0x60); // PUSH1
+    code.push(0x00);
+    code.push(0xf3); // RETURN
+
+    let input_offset = code.len() as u16;
+    code[4..6].copy_from_slice(&input_offset.to_be_bytes());
+
+    code.extend_from_slice(input);
+    code
 }
 
 fn deploy_contract(
@@ -1,5 +1,6 @@
 use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 use ark_ec::pairing::Pairing;
+use ark_ec::VariableBaseMSM;
 use ark_ff::UniformRand;
 use ark_std::test_rng;
 use std::time::Instant;
@@ -159,7 +160,7 @@ fn benchmark_operation(operation: &str, internal_runs: usize) -> f64 {
         "Pairing" => {
             let mut g1_inputs = Vec::with_capacity(internal_runs);
             let mut g2_inputs = Vec::with_capacity(internal_runs);
-            
+
             for _ in 0..internal_runs {
                 g1_inputs.push(G1Affine::rand(&mut rng));
                 g2_inputs.push(G2Affine::rand(&mut rng));
@@ -172,6 +173,61 @@ fn benchmark_operation(operation: &str, internal_runs: usize) -> f64 {
             }
             start
         }
+        op if op.starts_with("Pairing.batch") => {
+            // ECPAIRING computes a product of k pairings via a single multi-Miller-loop
+            // followed by one shared final exponentiation, not k independent pairings.
+            let k: usize = op.trim_start_matches("Pairing.batch").parse().unwrap();
+            let mut g1_inputs = Vec::with_capacity(internal_runs);
+            let mut g2_inputs = Vec::with_capacity(internal_runs);
+
+            for _ in 0..internal_runs {
+                g1_inputs.push((0..k).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>());
+                g2_inputs.push((0..k).map(|_| G2Affine::rand(&mut rng)).collect::<Vec<_>>());
+            }
+
+            let start = Instant::now();
+            for i in 0..internal_runs {
+                let result = Bn254::multi_pairing(g1_inputs[i].clone(), g2_inputs[i].clone());
+                std::hint::black_box(result);
+            }
+            start
+        }
+        op if op.starts_with("G1.msm") => {
+            // ECADD/ECMUL workloads are dominated by MSM patterns: sum_i scalar_i * point_i
+            // via VariableBaseMSM rather than n independent scalar multiplications.
+            let n: usize = op.trim_start_matches("G1.msm").parse().unwrap();
+            let mut bases_inputs = Vec::with_capacity(internal_runs);
+            let mut scalars_inputs = Vec::with_capacity(internal_runs);
+
+            for _ in 0..internal_runs {
+                bases_inputs.push((0..n).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>());
+                scalars_inputs.push((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+            }
+
+            let start = Instant::now();
+            for i in 0..internal_runs {
+                let result = G1::msm(&bases_inputs[i], &scalars_inputs[i]).unwrap();
+                std::hint::black_box(result);
+            }
+            start
+        }
+        op if op.starts_with("G2.msm") => {
+            let n: usize = op.trim_start_matches("G2.msm").parse().unwrap();
+            let mut bases_inputs = Vec::with_capacity(internal_runs);
+            let mut scalars_inputs = Vec::with_capacity(internal_runs);
+
+            for _ in 0..internal_runs {
+                bases_inputs.push((0..n).map(|_| G2Affine::rand(&mut rng)).collect::<Vec<_>>());
+                scalars_inputs.push((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+            }
+
+            let start = Instant::now();
+            for i in 0..internal_runs {
+                let result = G2::msm(&bases_inputs[i], &scalars_inputs[i]).unwrap();
+                std::hint::black_box(result);
+            }
+            start
+        }
         _ => {
             eprintln!("Error: Unknown operation '{}'", operation);
             std::process::exit(1);
@@ -193,8 +249,11 @@ fn main() {
                 .help("The operation to benchmark")
                 .required(true)
                 .value_parser([
-                    "FpMont.add", "FpMont.mul", "Fp2Mont.mul", "Fp6Mont.mul", 
-                    "Fp12Mont.mul", "G1.add", "G1.mul", "G2.add", "G2.mul", "Pairing"
+                    "FpMont.add", "FpMont.mul", "Fp2Mont.mul", "Fp6Mont.mul",
+                    "Fp12Mont.mul", "G1.add", "G1.mul", "G2.add", "G2.mul", "Pairing",
+                    "Pairing.batch2", "Pairing.batch4", "Pairing.batch8", "Pairing.batch16",
+                    "G1.msm4", "G1.msm16", "G1.msm64",
+                    "G2.msm4", "G2.msm16", "G2.msm64"
                 ])
         )
         .arg(
@@ -224,6 +283,16 @@ fn main() {
         "G2.add" => 20000,
         "G2.mul" => 800,
         "Pairing" => 200,
+        "Pairing.batch2" => 100,
+        "Pairing.batch4" => 50,
+        "Pairing.batch8" => 25,
+        "Pairing.batch16" => 12,
+        "G1.msm4" => 1000,
+        "G1.msm16" => 300,
+        "G1.msm64" => 80,
+        "G2.msm4" => 500,
+        "G2.msm16" => 150,
+        "G2.msm64" => 40,
         _ => 1000,
     };
 